@@ -0,0 +1,150 @@
+// args.rs - command line arguments for the `dysk` binary crate
+//
+// Parsed with clap's derive API. Help is printed by `help::print` rather
+// than clap's generated help, so the built-in help/version flags are
+// disabled and handled as plain booleans instead (see `run()`).
+//
+// `cli/` (this crate) is the real Lustre-Dysk binary; `src/` (the `dysk`
+// library crate) is kept around for binary compatibility with plain dysk.
+// `--interactive`, `--block-size`, `--total`, `--output` and `--posix` exist
+// only on the library crate's `Args`; this crate's `Args` doesn't have them,
+// and this crate has no `blocksize` or `interactive` module.
+
+use {
+    crate::{
+        cols::Cols,
+        filter::Filter,
+        sorting::Sorting,
+        units::Units,
+    },
+    clap::Parser,
+    std::{path::PathBuf, str::FromStr},
+};
+
+/// A boolean with an `Auto` state, used for flags (`--color`, `--remote-stats`)
+/// whose default behavior depends on context rather than being simply on or off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriBool {
+    Yes,
+    No,
+    Auto,
+}
+
+impl TriBool {
+    pub fn unwrap_or_else<F: FnOnce() -> bool>(self, f: F) -> bool {
+        match self {
+            Self::Yes => true,
+            Self::No => false,
+            Self::Auto => f(),
+        }
+    }
+}
+
+impl FromStr for TriBool {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "yes" | "true" => Ok(Self::Yes),
+            "no" | "false" => Ok(Self::No),
+            "auto" => Ok(Self::Auto),
+            _ => Err(format!("{:?} isn't one of yes, no, auto", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Parser)]
+#[command(author, version, about, disable_help_flag = true, disable_version_flag = true)]
+pub struct Args {
+    /// print help and exit
+    #[arg(long, short = 'h')]
+    pub help: bool,
+
+    /// print version and exit
+    #[arg(long, short = 'V')]
+    pub version: bool,
+
+    /// also show non normal filesystems (bind mounts, pseudo/read-only filesystems...)
+    #[arg(long, short)]
+    pub all: bool,
+
+    /// whether to have colors (auto, yes, no)
+    #[arg(long, default_value = "auto")]
+    pub color: TriBool,
+
+    /// limit characters to ascii ones
+    #[arg(long)]
+    pub ascii: bool,
+
+    /// whether to fetch remote stats (auto by default)
+    #[arg(long, default_value = "auto")]
+    pub remote_stats: TriBool,
+
+    /// list all available column names and exit
+    #[arg(long)]
+    pub list_cols: bool,
+
+    /// columns to display
+    #[arg(long, short, default_value_t)]
+    pub cols: Cols,
+
+    /// filter expression, e.g. "remote & use>80%"
+    #[arg(long, short)]
+    pub filter: Option<Filter>,
+
+    /// sort key(s), e.g. "size-desc,fs"
+    #[arg(long, short, default_value_t)]
+    pub sort: Sorting,
+
+    /// units to use: binary (default), si, or bytes
+    #[arg(long, default_value_t)]
+    pub units: Units,
+
+    /// output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// output as CSV
+    #[arg(long)]
+    pub csv: bool,
+
+    /// only consider the filesystem containing this path
+    pub path: Option<PathBuf>,
+
+    /// collect Lustre (MDT/OST/Client) information; unlike `--lustre-only`,
+    /// non-Lustre mounts are kept
+    #[arg(long)]
+    pub lustre: bool,
+
+    /// restrict the output to Lustre mounts
+    #[arg(long)]
+    pub lustre_only: bool,
+
+    /// show per-mount Lustre component (MDT/OST/Client) columns
+    #[arg(long)]
+    pub lustre_components: bool,
+
+    /// group OSTs/MDTs per Lustre filesystem into a rollup row
+    #[arg(long)]
+    pub lustre_summary: bool,
+
+    /// keep the per-mount rows when `--lustre-summary` is set, appending the
+    /// summary as a trailing section instead of replacing the detail rows
+    #[arg(long, requires = "lustre_summary")]
+    pub lustre_summary_append: bool,
+
+    /// bound how long to wait (in milliseconds) for the remote-aware stats
+    /// read; past it, a local-only read is used and remote mounts show no
+    /// stats. The bound applies to the read of all mounts at once, not to
+    /// each mount separately.
+    #[arg(long)]
+    pub timeout: Option<u64>,
+}
+
+impl Args {
+    /// Resolve whether to use color, taking `--color auto` tty-detection
+    /// into account.
+    pub fn color(&self) -> bool {
+        use std::io::IsTerminal;
+        self.color.unwrap_or_else(|| std::io::stdout().is_terminal())
+    }
+}
@@ -0,0 +1,102 @@
+// config.rs - layered configuration: `~/.config/dysk/dysk.toml` / `DYSK_CONFIG`
+//
+// `Args` is re-parsed from scratch on every invocation, so users who always
+// want the same `--sort`, `--filter`, `--cols`, `--units` have to retype
+// them. This reads an optional TOML config whose keys mirror those `Args`
+// fields, and merges it *under* the command line: a flag whose value differs
+// from its built-in default wins over the config (see `Config::apply`).
+//
+// The values are plain strings parsed through the exact same `FromStr`
+// impls `clap` already uses for these flags, so there's one source of truth
+// for parsing/validation between the CLI and the config file.
+
+use {
+    crate::{
+        args::Args,
+        sorting::Sorting,
+        units::Units,
+    },
+    serde::Deserialize,
+    std::{env, fs, path::PathBuf},
+};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    pub sort: Option<String>,
+    pub filter: Option<String>,
+    pub cols: Option<String>,
+    pub units: Option<String>,
+}
+
+impl Config {
+    /// `DYSK_CONFIG` overrides the default `~/.config/dysk/dysk.toml`.
+    pub fn path() -> Option<PathBuf> {
+        if let Ok(path) = env::var("DYSK_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+        let home = env::var("HOME").ok()?;
+        Some(PathBuf::from(home).join(".config/dysk/dysk.toml"))
+    }
+
+    /// Read and parse the config file, falling back to an empty (all-`None`)
+    /// config if it doesn't exist or doesn't parse - a missing or malformed
+    /// config should never stop `dysk` from running.
+    pub fn load() -> Config {
+        let Some(path) = Self::path() else {
+            return Config::default();
+        };
+        let Ok(raw) = fs::read_to_string(&path) else {
+            return Config::default();
+        };
+        match toml::from_str(&raw) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Warning: couldn't parse {:?}: {}", path, e);
+                Config::default()
+            }
+        }
+    }
+
+    /// Fill in any field `args` left at its built-in default from this
+    /// config. A CLI flag whose value differs from the default wins, since
+    /// it has already overwritten the default by the time this runs.
+    ///
+    /// For the non-`Option` fields (`sort`, `cols`, `units`) this can't
+    /// tell "explicitly passed the default value" apart from "not passed at
+    /// all", so a config value also replaces a flag passed with the default
+    /// value; only `filter`, already `Option<Filter>`, is unambiguous.
+    pub fn apply(&self, args: &mut Args) {
+        if args.filter.is_none() {
+            if let Some(raw) = &self.filter {
+                match raw.parse() {
+                    Ok(filter) => args.filter = Some(filter),
+                    Err(e) => eprintln!("Warning: invalid `filter` in config: {}", e),
+                }
+            }
+        }
+        if args.sort == Sorting::default() {
+            if let Some(raw) = &self.sort {
+                match raw.parse() {
+                    Ok(sort) => args.sort = sort,
+                    Err(e) => eprintln!("Warning: invalid `sort` in config: {}", e),
+                }
+            }
+        }
+        if args.units == Units::default() {
+            if let Some(raw) = &self.units {
+                match raw.parse() {
+                    Ok(units) => args.units = units,
+                    Err(e) => eprintln!("Warning: invalid `units` in config: {}", e),
+                }
+            }
+        }
+        if args.cols == crate::cols::Cols::default() {
+            if let Some(raw) = &self.cols {
+                match raw.parse() {
+                    Ok(cols) => args.cols = cols,
+                    Err(e) => eprintln!("Warning: invalid `cols` in config: {}", e),
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub mod args;
 pub mod col;
 pub mod col_expr;
 pub mod cols;
+pub mod config;
 pub mod csv;
 pub mod filter;
 pub mod help;
@@ -13,6 +14,7 @@ pub mod sorting;
 pub mod table;
 pub mod units;
 pub mod lustre;
+pub mod lustre_summary;
 
 use crate::lustre::{LustreData, MountLustreExt};
 
@@ -29,9 +31,64 @@ use {
 };
 
 
+/// Read mounts with `--remote-stats` honored, bounded by an optional
+/// `--timeout` in milliseconds.
+///
+/// `lfs_core` only exposes a batch-level `remote_stats(bool)` toggle, not a
+/// per-mount one, so there's no API to fetch one mount's stats in isolation
+/// or cancel a single stuck statvfs call mid-flight. What we *can* do
+/// without discarding already-good data: when both `--remote-stats` and a
+/// `--timeout` are in play, we first do a fast local-only read as a
+/// baseline (local filesystems never block the way a stuck NFS/Lustre
+/// server can), then race the remote-aware read against the timeout on a
+/// worker thread. If it doesn't make it back in time, we keep that
+/// local-only baseline instead of throwing away every mount's stats - only
+/// the remote ones end up unreachable, not mounts that would have answered
+/// instantly. The worker thread itself isn't force-joined (Rust has no way
+/// to cancel a blocked syscall); it's left to finish on its own and its
+/// late result, if any, is simply ignored.
+fn read_mounts_bounded(
+    remote_stats: bool,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<lfs_core::Mount>, Box<dyn std::error::Error>> {
+    if !remote_stats {
+        let mut options = lfs_core::ReadOptions::default();
+        options.remote_stats(false);
+        return Ok(lfs_core::read_mounts(&options)?);
+    }
+
+    let Some(timeout_ms) = timeout_ms else {
+        let mut options = lfs_core::ReadOptions::default();
+        options.remote_stats(true);
+        return Ok(lfs_core::read_mounts(&options)?);
+    };
+
+    let mut local_options = lfs_core::ReadOptions::default();
+    local_options.remote_stats(false);
+    let local_mounts = lfs_core::read_mounts(&local_options)?;
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut options = lfs_core::ReadOptions::default();
+        options.remote_stats(true);
+        let _ = tx.send(lfs_core::read_mounts(&options));
+    });
+    match rx.recv_timeout(std::time::Duration::from_millis(timeout_ms)) {
+        Ok(result) => Ok(result?),
+        Err(std::sync::mpsc::RecvTimeoutError::Timeout | std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+            // The remote-aware read didn't answer in time: fall back to
+            // the local-only baseline rather than discarding every mount's
+            // stats. Remote mounts report as unreachable (no stats); local
+            // ones keep the stats they already had.
+            Ok(local_mounts)
+        }
+    }
+}
+
 #[allow(clippy::match_like_matches_macro)]
 pub fn run() {
-    let args = Args::parse();
+    let mut args = Args::parse();
+    config::Config::load().apply(&mut args);
     if args.version {
         println!("dysk {}", env!("CARGO_PKG_VERSION"));
         return;
@@ -46,9 +103,7 @@ pub fn run() {
         csi_reset();
         return;
     }
-    let mut options = lfs_core::ReadOptions::default();
-    options.remote_stats(args.remote_stats.unwrap_or_else(||true));
-    let mut mounts = match lfs_core::read_mounts(&options) {
+    let mut mounts = match read_mounts_bounded(args.remote_stats.unwrap_or_else(||true), args.timeout) {
         Ok(mounts) => mounts,
         Err(e) => {
             eprintln!("Error reading mounts: {}", e);
@@ -94,6 +149,35 @@ pub fn run() {
             return;
         }
     };
+    // `--lustre-summary` rolls the per-mount rows up into one row per
+    // Lustre filesystem (OST/MDT counts, summed capacity, fill% spread).
+    // By default it replaces the detail rows; `--lustre-summary-append`
+    // keeps the detail rows and adds the summary as a trailing section.
+    // This has to be consulted before the --csv/--json branches below,
+    // not just the plain table path, or `--lustre-summary --csv`/`--json`
+    // would silently fall back to ordinary per-mount rows.
+    if args.lustre_summary && !(args.lustre || args.lustre_only || args.lustre_components) {
+        if let Err(e) = lustre_data.collect_lustre_info() {
+            eprintln!("Warning: Failed to collect Lustre information: {}", e);
+        }
+    }
+    let lustre_summary = args.lustre_summary.then(|| {
+        let owned: Vec<lfs_core::Mount> = mounts.iter().map(|m| (*m).clone()).collect();
+        lustre_summary::summarize(&owned, &lustre_data)
+    });
+    if let Some(summary) = &lustre_summary {
+        if args.csv {
+            lustre_summary::print_csv(summary, ',', args.units);
+        } else if args.json {
+            println!("{}", serde_json::to_string_pretty(&lustre_summary::json_value(summary, args.units)).unwrap());
+        } else {
+            lustre_summary::print(summary, args.units);
+        }
+        if !args.lustre_summary_append {
+            return;
+        }
+    }
+
     if args.csv {
         csv::print(&mounts, &args, &lustre_data).expect("writing csv failed");
         return;
@@ -0,0 +1,193 @@
+// lustre_summary.rs - `--lustre-summary` rollup/aggregation view
+//
+// The Lustre subsystem already classifies each mount as MDT/OST/Client with
+// a `component_index` (see `lustre.rs`). This module groups the per-mount
+// rows `run()` would otherwise print one-by-one into one synthetic summary
+// row per Lustre filesystem, so admins can spot an imbalanced OST at a
+// glance instead of eyeballing dozens of rows.
+
+use std::collections::HashMap;
+
+use crate::lustre::{LustreComponentType, LustreData, MountLustreExt};
+
+/// Aggregated stats for a single Lustre filesystem (grouped by its UUID).
+#[derive(Debug, Clone)]
+pub struct LustreSummary {
+    pub filesystem_uuid: String,
+    pub ost_count: usize,
+    pub mdt_count: usize,
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub available_bytes: u64,
+    pub min_fill_percent: f64,
+    pub max_fill_percent: f64,
+    pub avg_fill_percent: f64,
+}
+
+/// Group `mounts` by Lustre filesystem UUID and roll each group's OSTs up
+/// into one summary row. Mounts with no Lustre info (plain local/NFS
+/// mounts, or Lustre info unavailable) are skipped. Filesystems are
+/// returned in UUID order for stable output.
+pub fn summarize(mounts: &[lfs_core::Mount], lustre_data: &LustreData) -> Vec<LustreSummary> {
+    struct Accumulator {
+        ost_count: usize,
+        mdt_count: usize,
+        total_bytes: u64,
+        used_bytes: u64,
+        available_bytes: u64,
+        ost_fill_percents: Vec<f64>,
+    }
+
+    let mut by_uuid: HashMap<String, Accumulator> = HashMap::new();
+
+    for mount in mounts {
+        let Some(info) = mount.lustre_info(lustre_data) else {
+            continue;
+        };
+        let acc = by_uuid.entry(info.uuid.clone()).or_insert_with(|| Accumulator {
+            ost_count: 0,
+            mdt_count: 0,
+            total_bytes: 0,
+            used_bytes: 0,
+            available_bytes: 0,
+            ost_fill_percents: Vec::new(),
+        });
+
+        match info.component_type {
+            LustreComponentType::OST => {
+                acc.ost_count += 1;
+                if let Some(stats) = mount.stats() {
+                    acc.total_bytes += stats.size();
+                    acc.used_bytes += stats.used();
+                    acc.available_bytes += stats.available();
+                    acc.ost_fill_percents.push(stats.use_share() * 100.0);
+                }
+            }
+            LustreComponentType::MDT => {
+                acc.mdt_count += 1;
+            }
+            LustreComponentType::Client | LustreComponentType::Unknown => {}
+        }
+    }
+
+    let mut summaries: Vec<LustreSummary> = by_uuid
+        .into_iter()
+        .map(|(filesystem_uuid, acc)| {
+            let (min_fill_percent, max_fill_percent, avg_fill_percent) = fill_percent_stats(&acc.ost_fill_percents);
+            LustreSummary {
+                filesystem_uuid,
+                ost_count: acc.ost_count,
+                mdt_count: acc.mdt_count,
+                total_bytes: acc.total_bytes,
+                used_bytes: acc.used_bytes,
+                available_bytes: acc.available_bytes,
+                min_fill_percent,
+                max_fill_percent,
+                avg_fill_percent,
+            }
+        })
+        .collect();
+    summaries.sort_by(|a, b| a.filesystem_uuid.cmp(&b.filesystem_uuid));
+    summaries
+}
+
+/// `(min, max, avg)` of a filesystem's per-OST fill percentages, or all
+/// zeroes when it has no OSTs with stats (e.g. an all-MDT or unreachable
+/// filesystem) - split out of `summarize` so this pure math is unit-testable
+/// without needing a real `lfs_core::Mount`/`LustreData` fixture.
+fn fill_percent_stats(percents: &[f64]) -> (f64, f64, f64) {
+    if percents.is_empty() {
+        return (0.0, 0.0, 0.0);
+    }
+    let min = percents.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = percents.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let avg = percents.iter().sum::<f64>() / percents.len() as f64;
+    (min, max, avg)
+}
+
+/// Plain-text rendering of the summary rows, used for the table output path.
+pub fn print(summaries: &[LustreSummary], units: crate::units::Units) {
+    println!("{:<36} {:>5} {:>5} {:>10} {:>10} {:>10} {:>6} {:>6} {:>6}",
+        "filesystem", "osts", "mdts", "size", "used", "avail", "min%", "max%", "avg%");
+    for s in summaries {
+        println!("{:<36} {:>5} {:>5} {:>10} {:>10} {:>10} {:>5.0}% {:>5.0}% {:>5.0}%",
+            s.filesystem_uuid,
+            s.ost_count,
+            s.mdt_count,
+            units.fmt(s.total_bytes),
+            units.fmt(s.used_bytes),
+            units.fmt(s.available_bytes),
+            s.min_fill_percent,
+            s.max_fill_percent,
+            s.avg_fill_percent);
+    }
+}
+
+/// CSV rendering of the summary rows, so `--lustre-summary --csv` gets the
+/// rollup instead of silently falling back to ordinary per-mount rows.
+pub fn print_csv(summaries: &[LustreSummary], separator: char, units: crate::units::Units) {
+    let row = |fields: &[String]| fields.join(&separator.to_string());
+    println!("{}", row(&[
+        "filesystem_uuid".into(), "ost_count".into(), "mdt_count".into(), "size".into(),
+        "used".into(), "avail".into(), "min_fill_percent".into(), "max_fill_percent".into(), "avg_fill_percent".into(),
+    ]));
+    for s in summaries {
+        println!("{}", row(&[
+            s.filesystem_uuid.clone(),
+            s.ost_count.to_string(),
+            s.mdt_count.to_string(),
+            units.fmt(s.total_bytes),
+            units.fmt(s.used_bytes),
+            units.fmt(s.available_bytes),
+            format!("{:.2}", s.min_fill_percent),
+            format!("{:.2}", s.max_fill_percent),
+            format!("{:.2}", s.avg_fill_percent),
+        ]));
+    }
+}
+
+/// JSON rendering of the summary rows, so `--lustre-summary --json` gets the
+/// rollup instead of silently falling back to ordinary per-mount rows.
+pub fn json_value(summaries: &[LustreSummary], units: crate::units::Units) -> serde_json::Value {
+    serde_json::Value::Array(
+        summaries
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "filesystem_uuid": s.filesystem_uuid,
+                    "ost_count": s.ost_count,
+                    "mdt_count": s.mdt_count,
+                    "size": units.fmt(s.total_bytes),
+                    "used": units.fmt(s.used_bytes),
+                    "avail": units.fmt(s.available_bytes),
+                    "min_fill_percent": s.min_fill_percent,
+                    "max_fill_percent": s.max_fill_percent,
+                    "avg_fill_percent": s.avg_fill_percent,
+                })
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_percent_stats_empty() {
+        assert_eq!(fill_percent_stats(&[]), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_fill_percent_stats_single() {
+        assert_eq!(fill_percent_stats(&[42.0]), (42.0, 42.0, 42.0));
+    }
+
+    #[test]
+    fn test_fill_percent_stats_min_max_avg() {
+        let (min, max, avg) = fill_percent_stats(&[10.0, 90.0, 50.0]);
+        assert_eq!(min, 10.0);
+        assert_eq!(max, 90.0);
+        assert_eq!(avg, 50.0);
+    }
+}
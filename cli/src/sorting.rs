@@ -13,92 +13,137 @@ use {
     },
 };
 
-/// Sorting directive: the column and the order (asc or desc)
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// An ordered list of sort keys: the first key is primary, later keys only
+/// break ties left by the ones before them (`--sort size-desc,fs,dev`).
+#[derive(Debug, Clone, PartialEq)]
 pub struct Sorting {
-    col: Col,
-    order: Order,
+    keys: Vec<(Col, Order)>,
 }
 
 impl Default for Sorting {
     fn default() -> Self {
         let col = Col::default_sort_col();
         let order = col.default_sort_order();
-        Self { col, order }
+        Self { keys: vec![(col, order)] }
     }
 }
 
 impl Sorting {
-    pub fn sort(self, mounts: &mut [Mount]) {
-        let comparator = self.col.comparator();
-        mounts.sort_by(comparator);
-        if self.order == Order::Desc {
-            mounts.reverse();
-        }
+    /// Build a sorting with a single key.
+    pub fn new(col: Col, order: Order) -> Self {
+        Self { keys: vec![(col, order)] }
+    }
+
+    /// The primary (first) sort column.
+    pub fn col(&self) -> Col {
+        self.keys.first().map(|(col, _)| *col).unwrap_or_else(Col::default_sort_col)
+    }
+
+    /// The order of the primary (first) sort column.
+    pub fn order(&self) -> Order {
+        self.keys.first().map(|(_, order)| *order).unwrap_or(Order::Asc)
+    }
+
+    pub fn sort(&self, mounts: &mut [Mount]) {
+        mounts.sort_by(|a, b| self.compare(a, b));
+    }
+
+    /// Composite comparator: walk the keys in order, reversing each column's
+    /// individual `Ordering` when that key is `Desc`, and let later keys
+    /// only matter when earlier ones compare `Equal`. This is what makes
+    /// tie-breaking keys compose correctly, unlike reversing the whole
+    /// sorted slice at the end (which only works for a single key).
+    fn compare(&self, a: &Mount, b: &Mount) -> Ordering {
+        let per_key = self.keys.iter().map(|(col, order)| (col.comparator()(a, b), *order));
+        fold_keyed_orderings(per_key)
+    }
+
+    pub fn sort_with_lustre(&self, mounts: &mut [Mount], lustre_data: &LustreData) {
+        mounts.sort_by(|a, b| self.compare_with_lustre(a, b, lustre_data));
+    }
+
+    fn compare_with_lustre(&self, a: &Mount, b: &Mount, lustre_data: &LustreData) -> Ordering {
+        let per_key = self
+            .keys
+            .iter()
+            .map(|(col, order)| (Self::compare_one_with_lustre(*col, a, b, lustre_data), *order));
+        fold_keyed_orderings(per_key)
     }
-    
-    pub fn sort_with_lustre(self, mounts: &mut [Mount], lustre_data: &LustreData) {
-            if matches!(self.col, Col::LustreUuid | Col::LustreComponent | Col::LustreIndex) {
-                // Use custom Lustre sorting for Lustre columns
-                mounts.sort_by(|a, b| {
-                    match self.col {
-                        Col::LustreUuid => {
-                            match (a.lustre_info(lustre_data), b.lustre_info(lustre_data)) {
-                                (Some(a_info), Some(b_info)) => a_info.uuid.cmp(&b_info.uuid),
-                                (Some(_), None) => Ordering::Less,
-                                (None, Some(_)) => Ordering::Greater,
-                                (None, None) => Ordering::Equal,
-                            }
-                        },
-                        Col::LustreComponent => {
-                            match (a.lustre_info(lustre_data), b.lustre_info(lustre_data)) {
-                                (Some(a_info), Some(b_info)) => {
-                                    let a_order = match a_info.component_type {
-                                        crate::lustre::LustreComponentType::MDT => 0,
-                                        crate::lustre::LustreComponentType::OST => 1,
-                                        crate::lustre::LustreComponentType::Client => 2,
-                                        crate::lustre::LustreComponentType::Unknown => 3,
-                                    };
-                                    let b_order = match b_info.component_type {
-                                        crate::lustre::LustreComponentType::MDT => 0,
-                                        crate::lustre::LustreComponentType::OST => 1,
-                                        crate::lustre::LustreComponentType::Client => 2,
-                                        crate::lustre::LustreComponentType::Unknown => 3,
-                                    };
-                                    a_order.cmp(&b_order)
-                                },
-                                (Some(_), None) => Ordering::Less,
-                                (None, Some(_)) => Ordering::Greater,
-                                (None, None) => Ordering::Equal,
-                            }
-                        },
-                        Col::LustreIndex => {
-                            match (a.lustre_info(lustre_data), b.lustre_info(lustre_data)) {
-                                (Some(a_info), Some(b_info)) => {
-                                    match (a_info.component_index, b_info.component_index) {
-                                        (Some(a_idx), Some(b_idx)) => a_idx.cmp(&b_idx),
-                                        (Some(_), None) => Ordering::Less,
-                                        (None, Some(_)) => Ordering::Greater,
-                                        (None, None) => Ordering::Equal,
-                                    }
-                                },
-                                (Some(_), None) => Ordering::Less,
-                                (None, Some(_)) => Ordering::Greater,
-                                (None, None) => Ordering::Equal,
-                            }
-                        },
-                        _ => unreachable!(),
+
+    /// Per-column comparison, with Lustre-aware handling for the Lustre
+    /// columns (UUID/component/index) so they can be used as any key,
+    /// primary or tie-breaker, alongside regular columns.
+    fn compare_one_with_lustre(col: Col, a: &Mount, b: &Mount, lustre_data: &LustreData) -> Ordering {
+        match col {
+            Col::LustreUuid => {
+                match (a.lustre_info(lustre_data), b.lustre_info(lustre_data)) {
+                    (Some(a_info), Some(b_info)) => a_info.uuid.cmp(&b_info.uuid),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            }
+            Col::LustreComponent => {
+                match (a.lustre_info(lustre_data), b.lustre_info(lustre_data)) {
+                    (Some(a_info), Some(b_info)) => {
+                        let order_of = |c: &crate::lustre::LustreComponentType| match c {
+                            crate::lustre::LustreComponentType::MDT => 0,
+                            crate::lustre::LustreComponentType::OST => 1,
+                            crate::lustre::LustreComponentType::Client => 2,
+                            crate::lustre::LustreComponentType::Unknown => 3,
+                        };
+                        order_of(&a_info.component_type).cmp(&order_of(&b_info.component_type))
                     }
-                });
-            } else {
-                // Use regular sorting for non-Lustre columns
-                self.sort(mounts);
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
             }
-            
-            if self.order == Order::Desc {
-                mounts.reverse();
+            Col::LustreIndex => {
+                match (a.lustre_info(lustre_data), b.lustre_info(lustre_data)) {
+                    (Some(a_info), Some(b_info)) => {
+                        match (a_info.component_index, b_info.component_index) {
+                            (Some(a_idx), Some(b_idx)) => a_idx.cmp(&b_idx),
+                            (Some(_), None) => Ordering::Less,
+                            (None, Some(_)) => Ordering::Greater,
+                            (None, None) => Ordering::Equal,
+                        }
+                    }
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
             }
+            _ => col.comparator()(a, b),
         }
+    }
+}
+
+/// Combine one `Ordering` per sort key into the final composite ordering:
+/// reverse a key's ordering when it's `Desc`, and only let a later key
+/// matter when every earlier one compared `Equal`. Split out of
+/// `compare`/`compare_with_lustre` so this tie-breaking logic is testable
+/// without needing a real `Mount` to compare.
+fn fold_keyed_orderings(items: impl Iterator<Item = (Ordering, Order)>) -> Ordering {
+    items.fold(Ordering::Equal, |acc, (ord, order)| {
+        acc.then_with(|| if order == Order::Desc { ord.reverse() } else { ord })
+    })
+}
+
+/// Needed so `#[arg(default_value_t)]` on `Args::sort` (`default_value_t`
+/// calls `ToString` on the default to build the `--help` text) has something
+/// to call; this is display-only and isn't required to round-trip through
+/// `FromStr`.
+impl fmt::Display for Sorting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = self
+            .keys
+            .iter()
+            .map(|(col, order)| format!("{:?}-{:?}", col, order))
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}", rendered)
+    }
 }
 
 #[derive(Debug)]
@@ -121,28 +166,98 @@ impl fmt::Display for ParseSortingError {
 }
 impl error::Error for ParseSortingError {}
 
+/// Parse a single `col` or `col-order` directive, same grammar as before
+/// multi-key support: a column name, optionally followed by `-asc`/`-desc`.
+fn parse_key(raw: &str, s: &str) -> Result<(Col, Order), ParseSortingError> {
+    let cut_idx_len = s
+        .char_indices()
+        .find(|(_idx, c)| c.is_whitespace() || *c == '-')
+        .map(|(idx, c)| (idx, c.len_utf8()));
+    let (s_col, s_order) = match cut_idx_len {
+        Some((idx, len)) => (&s[..idx], Some(&s[idx+len..])),
+        None => (s, None),
+    };
+    let col: Col = s_col.parse()
+        .map_err(|pce| ParseSortingError::new(raw, Box::new(pce)))?;
+    let order = match s_order {
+        Some(s_order) => {
+            s_order.parse()
+                .map_err(|poe| ParseSortingError::new(raw, Box::new(poe)))?
+        }
+        None => {
+            col.default_sort_order()
+        }
+    };
+    Ok((col, order))
+}
+
 impl FromStr for Sorting {
     type Err = ParseSortingError;
     fn from_str(s: &str) -> Result<Self, ParseSortingError> {
-        let cut_idx_len = s
-            .char_indices()
-            .find(|(_idx, c)| c.is_whitespace() || *c == '-')
-            .map(|(idx, c)| (idx, c.len_utf8()));
-        let (s_col, s_order) = match cut_idx_len {
-            Some((idx, len)) => (&s[..idx], Some(&s[idx+len..])),
-            None => (s, None),
-        };
-        let col: Col = s_col.parse()
-            .map_err(|pce| ParseSortingError::new(s, Box::new(pce)))?;
-        let order = match s_order {
-            Some(s_order) => {
-                s_order.parse()
-                    .map_err(|poe| ParseSortingError::new(s, Box::new(poe)))?
-            }
-            None => {
-                col.default_sort_order()
-            }
-        };
-        Ok(Self { col, order })
+        let keys = s
+            .split(',')
+            .map(|part| {
+                let part = part.trim();
+                parse_key(part, part)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Err(ParseSortingError::new(s, "sort expression is empty"));
+        }
+        Ok(Self { keys })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_keyed_orderings_primary_key_decides() {
+        let ordering = fold_keyed_orderings([(Ordering::Less, Order::Asc), (Ordering::Greater, Order::Asc)].into_iter());
+        assert_eq!(ordering, Ordering::Less);
+    }
+
+    #[test]
+    fn test_fold_keyed_orderings_tie_break_on_second_key() {
+        let ordering = fold_keyed_orderings([(Ordering::Equal, Order::Asc), (Ordering::Greater, Order::Asc)].into_iter());
+        assert_eq!(ordering, Ordering::Greater);
+    }
+
+    #[test]
+    fn test_fold_keyed_orderings_desc_reverses_that_key_only() {
+        let ordering = fold_keyed_orderings([(Ordering::Less, Order::Desc)].into_iter());
+        assert_eq!(ordering, Ordering::Greater);
+
+        // a Desc tie-break key reverses only its own contribution, not the
+        // already-decided primary key
+        let ordering = fold_keyed_orderings([(Ordering::Less, Order::Asc), (Ordering::Less, Order::Desc)].into_iter());
+        assert_eq!(ordering, Ordering::Less);
+    }
+
+    #[test]
+    fn test_fold_keyed_orderings_all_equal_is_equal() {
+        let ordering = fold_keyed_orderings([(Ordering::Equal, Order::Asc), (Ordering::Equal, Order::Desc)].into_iter());
+        assert_eq!(ordering, Ordering::Equal);
+    }
+
+    #[test]
+    fn test_parse_single_key_defaults_order() {
+        let sorting: Sorting = "fs".parse().unwrap();
+        assert_eq!(sorting.keys.len(), 1);
+        assert_eq!(sorting.keys[0].0, Col::Filesystem);
+        assert_eq!(sorting.keys[0].1, Col::Filesystem.default_sort_order());
+    }
+
+    #[test]
+    fn test_parse_multi_key() {
+        let sorting: Sorting = "size-desc,fs".parse().unwrap();
+        assert_eq!(sorting.keys, vec![(Col::Size, Order::Desc), (Col::Filesystem, Order::Asc)]);
+    }
+
+    #[test]
+    fn test_parse_error_cites_only_the_offending_key() {
+        let err = "size-desc,bogus".parse::<Sorting>().unwrap_err();
+        assert_eq!(err.raw, "bogus");
     }
 }
@@ -0,0 +1,150 @@
+// args.rs - command line arguments for the `dysk` library crate
+//
+// Parsed with clap's derive API. Help is printed by `help::print` rather
+// than clap's generated help, so the built-in help/version flags are
+// disabled and handled as plain booleans instead (see `run()`).
+
+use {
+    crate::{
+        blocksize::BlockSize,
+        col::Col,
+        cols::Cols,
+        filter::Filter,
+        sorting::Sorting,
+        units::Units,
+    },
+    clap::Parser,
+    std::{path::PathBuf, str::FromStr},
+};
+
+/// A boolean with an `Auto` state, used for flags (`--color`, `--remote-stats`)
+/// whose default behavior depends on context (e.g. whether stdout is a tty)
+/// rather than being simply on or off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriBool {
+    Yes,
+    No,
+    Auto,
+}
+
+impl TriBool {
+    /// Resolve to a concrete bool, calling `f` to decide the `Auto` case.
+    pub fn unwrap_or_else<F: FnOnce() -> bool>(self, f: F) -> bool {
+        match self {
+            Self::Yes => true,
+            Self::No => false,
+            Self::Auto => f(),
+        }
+    }
+}
+
+impl FromStr for TriBool {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "yes" | "true" => Ok(Self::Yes),
+            "no" | "false" => Ok(Self::No),
+            "auto" => Ok(Self::Auto),
+            _ => Err(format!("{:?} isn't one of yes, no, auto", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Parser)]
+#[command(author, version, about, disable_help_flag = true, disable_version_flag = true)]
+pub struct Args {
+    /// print help and exit
+    #[arg(long, short = 'h')]
+    pub help: bool,
+
+    /// print version and exit
+    #[arg(long, short = 'V')]
+    pub version: bool,
+
+    /// also show non normal filesystems (bind mounts, pseudo/read-only filesystems...)
+    #[arg(long, short)]
+    pub all: bool,
+
+    /// whether to have colors (auto, yes, no)
+    #[arg(long, default_value = "auto")]
+    pub color: TriBool,
+
+    /// limit characters to ascii ones
+    #[arg(long)]
+    pub ascii: bool,
+
+    /// whether to fetch remote stats (auto by default, which fetches them)
+    #[arg(long, default_value = "auto")]
+    pub remote_stats: TriBool,
+
+    /// list all available column names and exit
+    #[arg(long)]
+    pub list_cols: bool,
+
+    /// columns to display
+    #[arg(long, short, default_value_t)]
+    pub cols: Cols,
+
+    /// filter expression, e.g. "remote & use>80%"
+    #[arg(long, short)]
+    pub filter: Option<Filter>,
+
+    /// sort key, e.g. "size-desc" (one column; a comma-separated list of
+    /// keys isn't accepted)
+    #[arg(long, short, default_value_t)]
+    pub sort: Sorting,
+
+    /// units to use: binary (default), si, or bytes
+    #[arg(long, default_value_t)]
+    pub units: Units,
+
+    /// output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// output as CSV
+    #[arg(long)]
+    pub csv: bool,
+
+    /// CSV separator
+    #[arg(long, default_value = ",")]
+    pub csv_separator: char,
+
+    /// only consider the filesystem containing this path
+    pub path: Option<PathBuf>, // positional
+
+    /// open a full-screen, navigable view of the mounts instead of a one-shot print
+    #[arg(long, short)]
+    pub interactive: bool,
+
+    /// display sizes in fixed blocks, e.g. "1M", "512", "4K" (overrides --units);
+    /// falls back to $DF_BLOCK_SIZE, $BLOCK_SIZE then $BLOCKSIZE when unset
+    #[arg(long)]
+    pub block_size: Option<BlockSize>,
+
+    /// append a grand-total row summing every displayed mount
+    #[arg(long)]
+    pub total: bool,
+
+    /// bound how long to wait (in milliseconds) for remote (network) mounts
+    /// to answer before falling back to a local-only read
+    #[arg(long)]
+    pub timeout: Option<u64>,
+
+    /// df-style column list, e.g. "source,fstype,size,pcent,target"
+    #[arg(long = "output", value_delimiter = ',')]
+    pub output_fields: Option<Vec<Col>>,
+
+    /// POSIX-portable output: fixed 512-byte-block columns with df's exact headers
+    #[arg(long, short = 'P', visible_alias = "portability")]
+    pub posix: bool,
+}
+
+impl Args {
+    /// Resolve whether to use color, taking `--color auto` tty-detection
+    /// into account.
+    pub fn color(&self) -> bool {
+        use std::io::IsTerminal;
+        self.color.unwrap_or_else(|| std::io::stdout().is_terminal())
+    }
+}
@@ -0,0 +1,169 @@
+// blocksize.rs - df-compatible fixed block sizes for --block-size and friends
+//
+// `Units` covers the "pick a human scale" cases (binary/SI/bytes), but some
+// users want sizes expressed as a count of fixed-size blocks, the way
+// `df --block-size=1M` or `df -B G` does. `BlockSize` is that fixed scale:
+// an explicit byte count plus the suffix used to render it.
+
+use std::{
+    env,
+    fmt,
+    str::FromStr,
+};
+
+/// A fixed block size, e.g. 1M (1_048_576 bytes, rendered with the `M` suffix).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockSize {
+    bytes: u64,
+    suffix: &'static str,
+}
+
+impl BlockSize {
+    pub fn bytes(self) -> u64 {
+        self.bytes
+    }
+
+    pub fn suffix(self) -> &'static str {
+        self.suffix
+    }
+
+    /// df's implicit default when nothing else says otherwise: 1024-byte blocks.
+    pub fn default_blocks() -> Self {
+        Self { bytes: 1024, suffix: "K" }
+    }
+
+    /// A block size with an empty suffix, used by `--posix` for its bare
+    /// `512-blocks` counts.
+    pub fn from_bytes_per_block(bytes: u64) -> Self {
+        Self { bytes, suffix: "" }
+    }
+
+    /// The bare block count, rounded up, without the suffix.
+    pub fn blocks(self, bytes: u64) -> u64 {
+        bytes.div_ceil(self.bytes)
+    }
+
+    /// Resolve the effective block size the way coreutils does: an explicit
+    /// `--block-size` flag wins, then `DF_BLOCK_SIZE`, then `BLOCK_SIZE`,
+    /// then `BLOCKSIZE`, and finally the built-in default.
+    pub fn resolve(explicit: Option<BlockSize>) -> BlockSize {
+        Self::resolve_opt(explicit).unwrap_or_else(Self::default_blocks)
+    }
+
+    /// Same precedence as `resolve`, but returns `None` when nothing (flag
+    /// or env var) asked for a fixed block size, so callers can fall back to
+    /// `--units` formatting instead of silently defaulting to 1K blocks.
+    pub fn resolve_opt(explicit: Option<BlockSize>) -> Option<BlockSize> {
+        if let Some(bs) = explicit {
+            return Some(bs);
+        }
+        for var in ["DF_BLOCK_SIZE", "BLOCK_SIZE", "BLOCKSIZE"] {
+            if let Ok(value) = env::var(var) {
+                if let Ok(bs) = value.parse() {
+                    return Some(bs);
+                }
+            }
+        }
+        None
+    }
+
+    /// Format `bytes` as a bare count of this block size, rounding up so a
+    /// partially-filled trailing block still counts as a whole one. Like
+    /// `df --block-size`, this never appends the unit suffix (`--block-size
+    /// 1M` on a ~2 GiB filesystem prints `2048`, not `2048M`); the suffix is
+    /// only available through `suffix()`.
+    pub fn format(self, bytes: u64) -> String {
+        self.blocks(bytes).to_string()
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseBlockSizeError(String);
+
+impl fmt::Display for ParseBlockSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} isn't a valid block size (try K, M, G, T, KB, MB, GB, or a raw byte count)", self.0)
+    }
+}
+impl std::error::Error for ParseBlockSizeError {}
+
+impl FromStr for BlockSize {
+    type Err = ParseBlockSizeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseBlockSizeError(s.to_string());
+        let digits_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (digits, suffix) = s.split_at(digits_end);
+        if digits.is_empty() {
+            return Err(err());
+        }
+        let count: u64 = digits.parse().map_err(|_| err())?;
+        let (bytes, suffix) = match suffix.to_uppercase().as_str() {
+            "" => (count, "B"),
+            "K" => (count * 1024, "K"),
+            "M" => (count * 1024 * 1024, "M"),
+            "G" => (count * 1024 * 1024 * 1024, "G"),
+            "T" => (count * 1024 * 1024 * 1024 * 1024, "T"),
+            "KB" => (count * 1000, "KB"),
+            "MB" => (count * 1_000_000, "MB"),
+            "GB" => (count * 1_000_000_000, "GB"),
+            _ => return Err(err()),
+        };
+        let suffix = match suffix {
+            "B" => "B",
+            "K" => "K",
+            "M" => "M",
+            "G" => "G",
+            "T" => "T",
+            "KB" => "KB",
+            "MB" => "MB",
+            "GB" => "GB",
+            _ => unreachable!(),
+        };
+        Ok(Self { bytes, suffix })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_suffixes() {
+        assert_eq!("512".parse::<BlockSize>().unwrap(), BlockSize { bytes: 512, suffix: "B" });
+        assert_eq!("1K".parse::<BlockSize>().unwrap(), BlockSize { bytes: 1024, suffix: "K" });
+        assert_eq!("4k".parse::<BlockSize>().unwrap(), BlockSize { bytes: 4096, suffix: "K" });
+        assert_eq!("2M".parse::<BlockSize>().unwrap(), BlockSize { bytes: 2 * 1024 * 1024, suffix: "M" });
+        assert_eq!("1G".parse::<BlockSize>().unwrap(), BlockSize { bytes: 1024 * 1024 * 1024, suffix: "G" });
+        assert_eq!("1T".parse::<BlockSize>().unwrap(), BlockSize { bytes: 1024u64.pow(4), suffix: "T" });
+        assert_eq!("1KB".parse::<BlockSize>().unwrap(), BlockSize { bytes: 1000, suffix: "KB" });
+        assert_eq!("1MB".parse::<BlockSize>().unwrap(), BlockSize { bytes: 1_000_000, suffix: "MB" });
+        assert_eq!("1GB".parse::<BlockSize>().unwrap(), BlockSize { bytes: 1_000_000_000, suffix: "GB" });
+    }
+
+    #[test]
+    fn test_from_str_rejects_garbage() {
+        assert!("".parse::<BlockSize>().is_err());
+        assert!("K".parse::<BlockSize>().is_err());
+        assert!("1X".parse::<BlockSize>().is_err());
+    }
+
+    #[test]
+    fn test_format_rounds_up() {
+        // `format` never appends the suffix, matching `df --block-size`:
+        // `--block-size=1M` on a ~2 GiB filesystem prints `2048`, not `2048M`.
+        let bs = BlockSize { bytes: 1024 * 1024, suffix: "M" };
+        let two_gib = 2 * 1024 * 1024 * 1024u64;
+        assert_eq!(bs.format(0), "0");
+        assert_eq!(bs.format(1), "1");
+        assert_eq!(bs.format(two_gib), "2048");
+        assert_eq!(bs.format(two_gib + 1), "2049");
+    }
+
+    #[test]
+    fn test_blocks_has_no_suffix() {
+        let bs = BlockSize::from_bytes_per_block(512);
+        assert_eq!(bs.blocks(1024), 2);
+        assert_eq!(bs.blocks(1025), 3);
+        assert_eq!(bs.format(1024), "2");
+    }
+}
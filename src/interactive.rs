@@ -0,0 +1,247 @@
+// interactive.rs - full-screen TUI mode for browsing mounts
+//
+// This is the event-loop backing `dysk -i` / `dysk --interactive`. It reuses
+// the same filtered/sorted mount list as the one-shot `print_output` path,
+// but lets the user navigate, re-sort and re-filter live instead of getting
+// a single static table.
+
+use {
+    crate::{
+        col::Col,
+        get_filtered_mounts,
+        order::Order,
+        sorting::Sorting,
+        Args, Filter, MountLike,
+    },
+    std::io::{self, Write},
+    termimad::crossterm::{
+        cursor,
+        event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+        execute, queue,
+        style::Print,
+        terminal::{self, ClearType},
+    },
+};
+
+/// What the interactive session ended up doing.
+pub enum Outcome {
+    /// The user picked a row; print its mount point so it can be used in
+    /// shell command substitution (`cd "$(dysk -i)"`).
+    Selected(String),
+    /// The user quit without picking anything.
+    Cancelled,
+}
+
+/// State for the live session: the current args (mutated as the user
+/// re-sorts/re-filters/toggles `--all`), the cursor position, and the
+/// in-progress filter text being edited on the bottom line.
+struct State {
+    args: Args,
+    mounts: Vec<lfs_core::Mount>,
+    selected: usize,
+    editing_filter: bool,
+    filter_input: String,
+}
+
+impl State {
+    fn new(args: Args) -> Result<Self, Box<dyn std::error::Error>> {
+        let mounts = get_filtered_mounts(&args)?;
+        Ok(Self {
+            filter_input: args
+                .filter
+                .as_ref()
+                .map(|f| f.to_string())
+                .unwrap_or_default(),
+            args,
+            mounts,
+            selected: 0,
+            editing_filter: false,
+        })
+    }
+
+    fn reload(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.mounts = get_filtered_mounts(&self.args)?;
+        if self.selected >= self.mounts.len() {
+            self.selected = self.mounts.len().saturating_sub(1);
+        }
+        Ok(())
+    }
+
+    fn move_cursor(&mut self, delta: isize) {
+        if self.mounts.is_empty() {
+            return;
+        }
+        let len = self.mounts.len() as isize;
+        let mut pos = self.selected as isize + delta;
+        pos = pos.clamp(0, len - 1);
+        self.selected = pos as usize;
+    }
+
+    /// Re-sort by the column bound to `key`, toggling the order if it's
+    /// already the active sort column (mirrors how `Order`/`Sorting` are
+    /// parsed from the `--sort` flag).
+    fn sort_by_key(&mut self, col: Col) -> Result<(), Box<dyn std::error::Error>> {
+        let order = if self.args.sort.col() == col {
+            self.args.sort.order().reverse()
+        } else {
+            col.default_sort_order()
+        };
+        self.args.sort = Sorting::new(col, order);
+        self.reload()
+    }
+
+    fn toggle_all(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.args.all = !self.args.all;
+        self.reload()
+    }
+
+    fn apply_filter_input(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.filter_input.trim().is_empty() {
+            self.args.filter = None;
+        } else {
+            self.args.filter = Some(self.filter_input.parse::<Filter>()?);
+        }
+        self.reload()
+    }
+}
+
+/// Column-to-key bindings for live re-sorting, e.g. pressing `s` sorts by
+/// `Col::Size`, pressing it again reverses the order.
+const SORT_BINDINGS: &[(char, Col)] = &[
+    ('f', Col::Filesystem),
+    ('t', Col::Type),
+    ('s', Col::Size),
+    ('u', Col::Use),
+    ('r', Col::Free),
+    ('m', Col::MountPoint),
+];
+
+/// Run the interactive browser. On success, returns whether a mount point
+/// was selected (to be printed by the caller after the terminal is restored).
+pub fn run(args: Args) -> Result<Outcome, Box<dyn std::error::Error>> {
+    let mut state = State::new(args)?;
+
+    terminal::enable_raw_mode()?;
+    let mut out = io::stdout();
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let outcome = event_loop(&mut out, &mut state);
+
+    execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+
+    outcome
+}
+
+fn event_loop(out: &mut impl Write, state: &mut State) -> Result<Outcome, Box<dyn std::error::Error>> {
+    loop {
+        render(out, state)?;
+
+        if let Event::Key(KeyEvent { code, modifiers, .. }) = event::read()? {
+            if state.editing_filter {
+                match code {
+                    KeyCode::Enter => {
+                        state.editing_filter = false;
+                        if let Err(e) = state.apply_filter_input() {
+                            // Keep editing so the user can fix the expression.
+                            state.editing_filter = true;
+                            let _ = e; // not displayed; the prompt stays open with the input unchanged
+                        }
+                    }
+                    KeyCode::Esc => state.editing_filter = false,
+                    KeyCode::Backspace => {
+                        state.filter_input.pop();
+                    }
+                    KeyCode::Char(c) => state.filter_input.push(c),
+                    _ => {}
+                }
+                continue;
+            }
+
+            match code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(Outcome::Cancelled),
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(Outcome::Cancelled)
+                }
+                KeyCode::Up => state.move_cursor(-1),
+                KeyCode::Down => state.move_cursor(1),
+                KeyCode::Char('a') => state.toggle_all()?,
+                KeyCode::Char('/') => {
+                    state.editing_filter = true;
+                }
+                KeyCode::Enter => {
+                    if let Some(mount) = state.mounts.get(state.selected) {
+                        return Ok(Outcome::Selected(mount.mount_point()));
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if let Some((_, col)) = SORT_BINDINGS.iter().find(|(k, _)| *k == c) {
+                        state.sort_by_key(*col)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn render(out: &mut impl Write, state: &State) -> Result<(), Box<dyn std::error::Error>> {
+    queue!(out, cursor::MoveTo(0, 0), terminal::Clear(ClearType::All))?;
+
+    let header: String = state
+        .args
+        .cols
+        .cols()
+        .iter()
+        .map(|col| format!("{:<14}", col.title()))
+        .collect();
+    queue!(out, Print(&header), cursor::MoveToNextLine(1))?;
+
+    for (idx, mount) in state.mounts.iter().enumerate() {
+        let marker = if idx == state.selected { ">" } else { " " };
+        let row: String = state
+            .args
+            .cols
+            .cols()
+            .iter()
+            .map(|col| format!("{:<14}", cell_text(mount, *col)))
+            .collect();
+        queue!(out, Print(format!("{marker} {row}")), cursor::MoveToNextLine(1))?;
+    }
+
+    let status = if state.editing_filter {
+        format!("/{}_", state.filter_input)
+    } else {
+        format!(
+            "[{}] sort:{} all:{}  (↑/↓ move, f/t/s/u/r/m sort, a toggle-all, / filter, Enter pick, q quit)",
+            state.mounts.len(),
+            state.args.sort.col().title(),
+            state.args.all,
+        )
+    };
+    queue!(out, cursor::MoveToNextLine(1), Print(status))?;
+
+    out.flush()?;
+    Ok(())
+}
+
+fn cell_text(mount: &lfs_core::Mount, col: Col) -> String {
+    match col {
+        Col::Filesystem => mount.filesystem_name(),
+        Col::Type => mount.filesystem_type(),
+        Col::Size => mount
+            .total_bytes()
+            .map(|b| b.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        Col::Use | Col::UsePercent => mount
+            .usage_percentage()
+            .map(|p| format!("{p:.0}%"))
+            .unwrap_or_else(|| "-".to_string()),
+        Col::Free => mount
+            .available_bytes()
+            .map(|b| b.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        Col::MountPoint => mount.mount_point(),
+        _ => String::new(),
+    }
+}
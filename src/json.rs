@@ -0,0 +1,13 @@
+// json.rs - JSON rendering for `dysk --json`
+//
+// Same bridging story as `csv.rs`: `run()`/`print_output()` hold a concrete
+// `&[&Mount]` slice, while the `--total`/`--output` aware JSON value is built
+// by `table::build_generic_json_value`, which is generic over `MountLike`.
+
+use crate::{table::build_generic_json_value, Args};
+use lfs_core::Mount;
+
+pub fn output_value(mounts: &[&Mount], args: &Args) -> serde_json::Value {
+    let owned: Vec<Mount> = mounts.iter().map(|m| (*m).clone()).collect();
+    build_generic_json_value(&owned, args)
+}
@@ -5,12 +5,14 @@
 
 // Re-export all the core modules
 pub mod args;
+pub mod blocksize;
 pub mod col;
 pub mod col_expr;
 pub mod cols;
 pub mod csv;
 pub mod filter;
 pub mod help;
+pub mod interactive;
 pub mod json;
 pub mod list_cols;
 pub mod normal;
@@ -21,6 +23,7 @@ pub mod units;
 
 // Re-export commonly used types for easier access
 pub use args::{Args, TriBool};
+pub use blocksize::BlockSize;
 pub use col::Col;
 pub use cols::Cols;
 pub use filter::Filter;
@@ -50,6 +53,13 @@ pub trait MountLike: Debug + Clone {
     fn usage_percentage(&self) -> Option<f64>;
     fn filesystem_type(&self) -> String;
     fn is_normal(&self) -> bool;
+
+    /// Whether stats collection for this mount timed out or otherwise
+    /// failed, as opposed to the mount genuinely having no stats to report.
+    /// Defaults to `false` for mount-likes that don't track this.
+    fn is_unreachable(&self) -> bool {
+        false
+    }
 }
 
 // Implement for the existing lfs_core::Mount
@@ -89,6 +99,10 @@ impl MountLike for lfs_core::Mount {
     fn is_normal(&self) -> bool {
         normal::is_normal(self)
     }
+
+    fn is_unreachable(&self) -> bool {
+        lfs_core::Mount::is_unreachable(self)
+    }
 }
 
 // Generic table printing function
@@ -107,14 +121,65 @@ pub fn print_generic_table<T: MountLike>(
 }
 
 
+/// Read mounts with `--remote-stats` honored, bounded by an optional
+/// `--timeout` in milliseconds.
+///
+/// `lfs_core` only exposes a batch-level `remote_stats(bool)` toggle, not a
+/// per-mount one, so there's no API to fetch one mount's stats in isolation
+/// or cancel a single stuck statvfs call mid-flight. What we *can* do
+/// without discarding already-good data: when both `--remote-stats` and a
+/// `--timeout` are in play, we first do a fast local-only read as a
+/// baseline (local filesystems never block the way a stuck NFS/Lustre
+/// server can), then race the remote-aware read against the timeout on a
+/// worker thread. If it doesn't make it back in time, we keep that
+/// local-only baseline instead of throwing away every mount's stats - only
+/// the remote ones end up unreachable, not mounts that would have answered
+/// instantly. The worker thread itself isn't force-joined (Rust has no way
+/// to cancel a blocked syscall); it's left to finish on its own and its
+/// late result, if any, is simply ignored.
+fn read_mounts_bounded(
+    remote_stats: bool,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<lfs_core::Mount>, Box<dyn std::error::Error>> {
+    if !remote_stats {
+        let mut options = lfs_core::ReadOptions::default();
+        options.remote_stats(false);
+        return Ok(lfs_core::read_mounts(&options)?);
+    }
+
+    let Some(timeout_ms) = timeout_ms else {
+        let mut options = lfs_core::ReadOptions::default();
+        options.remote_stats(true);
+        return Ok(lfs_core::read_mounts(&options)?);
+    };
+
+    let mut local_options = lfs_core::ReadOptions::default();
+    local_options.remote_stats(false);
+    let local_mounts = lfs_core::read_mounts(&local_options)?;
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut options = lfs_core::ReadOptions::default();
+        options.remote_stats(true);
+        let _ = tx.send(lfs_core::read_mounts(&options));
+    });
+    match rx.recv_timeout(std::time::Duration::from_millis(timeout_ms)) {
+        Ok(result) => Ok(result?),
+        Err(std::sync::mpsc::RecvTimeoutError::Timeout | std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+            // The remote-aware read didn't answer in time: fall back to
+            // the local-only baseline rather than discarding every mount's
+            // stats. Remote mounts report as unreachable (no stats); local
+            // ones keep the stats they already had.
+            Ok(local_mounts)
+        }
+    }
+}
+
 /// Core dysk functionality as a library function
 /// This allows other crates to use dysk's logic programmatically
 pub fn get_mounts(args: &Args) -> Result<Vec<lfs_core::Mount>, Box<dyn std::error::Error>> {
-    let mut options = lfs_core::ReadOptions::default();
-    options.remote_stats(args.remote_stats.unwrap_or_else(|| true));
-    
-    let mut mounts = lfs_core::read_mounts(&options)?;
-    
+    let mut mounts = read_mounts_bounded(args.remote_stats.unwrap_or_else(|| true), args.timeout)?;
+
     if !args.all {
         mounts.retain(is_normal);
     }
@@ -151,7 +216,7 @@ pub fn print_output(mounts: &[lfs_core::Mount], args: &Args) -> Result<(), Box<d
     } else if args.json {
         println!(
             "{}",
-            serde_json::to_string_pretty(&json::output_value(&mount_refs, args.units))?
+            serde_json::to_string_pretty(&json::output_value(&mount_refs, args))?
         );
     } else if mount_refs.is_empty() {
         println!("no mount to display - try\n    dysk -a");
@@ -179,7 +244,13 @@ pub fn run_dysk(args: Args) -> Result<Vec<lfs_core::Mount>, Box<dyn std::error::
         list_cols::print(args.color(), args.ascii);
         return Ok(Vec::new());
     }
-    
+    if args.interactive {
+        if let interactive::Outcome::Selected(mount_point) = interactive::run(args.clone())? {
+            println!("{mount_point}");
+        }
+        return Ok(Vec::new());
+    }
+
     let mounts = get_filtered_mounts(&args)?;
     print_output(&mounts, &args)?;
     
@@ -206,10 +277,16 @@ pub fn run() {
         csi_reset();
         return;
     }
-    
-    let mut options = lfs_core::ReadOptions::default();
-    options.remote_stats(args.remote_stats.unwrap_or_else(|| true));
-    let mut mounts = match lfs_core::read_mounts(&options) {
+    if args.interactive {
+        match interactive::run(args) {
+            Ok(interactive::Outcome::Selected(mount_point)) => println!("{mount_point}"),
+            Ok(interactive::Outcome::Cancelled) => {}
+            Err(e) => eprintln!("Error in interactive mode: {}", e),
+        }
+        return;
+    }
+
+    let mut mounts = match read_mounts_bounded(args.remote_stats.unwrap_or_else(|| true), args.timeout) {
         Ok(mounts) => mounts,
         Err(e) => {
             eprintln!("Error reading mounts: {}", e);
@@ -251,7 +328,7 @@ pub fn run() {
     if args.json {
         println!(
             "{}",
-            serde_json::to_string_pretty(&json::output_value(&mount_refs, args.units)).unwrap()
+            serde_json::to_string_pretty(&json::output_value(&mount_refs, &args)).unwrap()
         );
         return;
     }
@@ -327,6 +404,12 @@ impl ArgsBuilder {
                 csv: false,
                 csv_separator: ',',
                 path: None,
+                interactive: false,
+                block_size: None,
+                total: false,
+                timeout: None,
+                output_fields: None,
+                posix: false,
             },
         }
     }
@@ -383,7 +466,7 @@ pub fn get_mounts_json() -> Result<String, Box<dyn std::error::Error>> {
     let args = ArgsBuilder::new().json(true).build();
     let mounts = get_mounts(&args)?;
     let mount_refs: Vec<&lfs_core::Mount> = mounts.iter().collect();
-    Ok(serde_json::to_string_pretty(&json::output_value(&mount_refs, args.units))?)
+    Ok(serde_json::to_string_pretty(&json::output_value(&mount_refs, &args))?)
 }
 
 /// Get all normal mounts (filtered like default dysk output)
@@ -1,6 +1,6 @@
 use {
     crate::{
-        Args, col::Col,
+        Args, Units, col::Col,
     },
     lfs_core::*,
     termimad::{
@@ -26,42 +26,166 @@ pub mod table {
     use super::*;
     
     pub fn print_generic<T: MountLike>(mounts: &[T], use_color: bool, args: &Args) {
-        print_header_generic(use_color);
-        
+        if args.posix {
+            return print_posix(mounts, args);
+        }
+
+        print_header_generic(use_color, args);
+
         for mount in mounts {
             print_row_generic(mount, use_color, args);
         }
-        
+
+        if args.total {
+            print_total_row_generic(mounts, use_color, args);
+        }
+
         if use_color {
             print!("\x1b[0m"); // Reset colors
         }
     }
-    
-    fn print_header_generic(use_color: bool) {
+
+    /// `df -P`/`--portability`: a fixed, space-tolerant, 512-byte-block
+    /// column set with df's exact header spellings, for feeding legacy
+    /// parsers that don't know about `--cols`/`--output`.
+    fn print_posix<T: MountLike>(mounts: &[T], args: &Args) {
+        let block = crate::blocksize::BlockSize::from_bytes_per_block(512);
+        println!("{:<30} {:>12} {:>12} {:>12} {:>8} {}",
+            "Filesystem", "512-blocks", "Used", "Available", "Capacity", "Mounted on");
+        for mount in mounts {
+            if let (Some(total), Some(used), Some(avail)) =
+                (mount.total_bytes(), mount.used_bytes(), mount.available_bytes())
+            {
+                let pct = mount.usage_percentage().unwrap_or(0.0);
+                println!("{:<30} {:>12} {:>12} {:>12} {:>7.0}% {}",
+                    mount.filesystem_name(),
+                    block.blocks(total),
+                    block.blocks(used),
+                    block.blocks(avail),
+                    pct,
+                    mount.mount_point());
+            } else {
+                println!("{:<30} {:>12} {:>12} {:>12} {:>8} {}",
+                    mount.filesystem_name(), "-", "-", "-", "-", mount.mount_point());
+            }
+        }
+        if args.total {
+            if let Some((total, used, avail, usage_pct)) = sum_totals(mounts) {
+                println!("{:<30} {:>12} {:>12} {:>12} {:>7.0}% {}",
+                    "total", block.blocks(total), block.blocks(used), block.blocks(avail), usage_pct, "");
+            }
+        }
+    }
+
+    /// Render the `--output`-selected columns for a single mount the way
+    /// `table::print` does for the termimad table, but for the plain
+    /// `MountLike`-generic path. Columns that need info beyond `MountLike`
+    /// (inode/device/uuid details) render as `-`.
+    pub(super) fn generic_cell<T: MountLike>(mount: &T, col: Col, units: Units, block_size: Option<crate::blocksize::BlockSize>) -> String {
+        match col {
+            Col::Filesystem => mount.filesystem_name(),
+            Col::Type => mount.filesystem_type(),
+            Col::MountPoint => mount.mount_point(),
+            Col::Size => mount.total_bytes().map(|b| format_amount(b, units, block_size)).unwrap_or_else(|| "-".to_string()),
+            Col::Used => mount.used_bytes().map(|b| format_amount(b, units, block_size)).unwrap_or_else(|| "-".to_string()),
+            Col::Free => mount.available_bytes().map(|b| format_amount(b, units, block_size)).unwrap_or_else(|| "-".to_string()),
+            Col::Use | Col::UsePercent => mount
+                .usage_percentage()
+                .map(|p| format!("{:.0}%", p))
+                .unwrap_or_else(|| "-".to_string()),
+            _ => "-".to_string(),
+        }
+    }
+
+    /// Render one `--output` column of the `--total` aggregate row. There's
+    /// no real `Mount` backing this row, so unlike `generic_cell` it's fed
+    /// the already-summed totals directly; columns that aren't part of the
+    /// total (inode/device/uuid details) render as `-`, same as `generic_cell`.
+    pub(super) fn total_row_cell(col: Col, total: u64, used: u64, avail: u64, usage_pct: f64, units: Units, block_size: Option<crate::blocksize::BlockSize>) -> String {
+        match col {
+            Col::Filesystem => "total".to_string(),
+            Col::Size => format_amount(total, units, block_size),
+            Col::Used => format_amount(used, units, block_size),
+            Col::Free => format_amount(avail, units, block_size),
+            Col::Use | Col::UsePercent => format!("{:.0}%", usage_pct),
+            _ => "-".to_string(),
+        }
+    }
+
+    /// Sum of `total`/`used`/`available` bytes across every mount that has
+    /// stats, plus the combined usage percentage (`100 * sum(used) / sum(total)`).
+    /// This backs the `--total` aggregate row, mirroring `df --total`.
+    pub(super) fn sum_totals<T: MountLike>(mounts: &[T]) -> Option<(u64, u64, u64, f64)> {
+        let mut total = 0u64;
+        let mut used = 0u64;
+        let mut avail = 0u64;
+        let mut any = false;
+        for mount in mounts {
+            if let (Some(t), Some(u), Some(a)) =
+                (mount.total_bytes(), mount.used_bytes(), mount.available_bytes())
+            {
+                total += t;
+                used += u;
+                avail += a;
+                any = true;
+            }
+        }
+        if !any {
+            return None;
+        }
+        let pct = if total > 0 { used as f64 / total as f64 * 100.0 } else { 0.0 };
+        Some((total, used, avail, pct))
+    }
+
+    fn print_total_row_generic<T: MountLike>(mounts: &[T], use_color: bool, args: &Args) {
+        let Some((total, used, avail, usage_pct)) = sum_totals(mounts) else {
+            return;
+        };
+        let (size_str, used_str, avail_str) = format_sizes(total, used, avail, args.units, args.block_size);
+        let usage_str = format!("{:.0}%", usage_pct);
+        println!("{:<30} {:>8} {:>8} {:>8} {:>5} {:>8} {}",
+            "total", "", size_str, used_str, usage_str, avail_str, "");
+    }
+
+    fn print_header_generic(use_color: bool, args: &Args) {
         if use_color {
             print!("\x1b[1m"); // Bold
         }
-        
-        println!("{:<30} {:>8} {:>8} {:>8} {:>5} {:>8} {}",
-            "filesystem", "type", "size", "used", "use%", "avail", "mounted on");
-        
+
+        if let Some(fields) = &args.output_fields {
+            let header: String = fields.iter().map(|col| format!("{:<12} ", col.title())).collect();
+            println!("{}", header.trim_end());
+        } else {
+            println!("{:<30} {:>8} {:>8} {:>8} {:>5} {:>8} {}",
+                "filesystem", "type", "size", "used", "use%", "avail", "mounted on");
+        }
+
         if use_color {
             print!("\x1b[0m"); // Reset
         }
     }
-    
+
     fn print_row_generic<T: MountLike>(mount: &T, use_color: bool, args: &Args) {
+        if let Some(fields) = &args.output_fields {
+            let row: String = fields
+                .iter()
+                .map(|col| format!("{:<12} ", generic_cell(mount, *col, args.units, args.block_size)))
+                .collect();
+            println!("{}", row.trim_end());
+            return;
+        }
+
         let fs_name = truncate_string(&mount.filesystem_name(), 30);
         let fs_type = mount.filesystem_type();
-        
-        if let (Some(total), Some(used), Some(avail)) = 
+
+        if let (Some(total), Some(used), Some(avail)) =
             (mount.total_bytes(), mount.used_bytes(), mount.available_bytes()) {
-            
-            let (size_str, used_str, avail_str) = format_sizes(total, used, avail, args.units);
-            
+
+            let (size_str, used_str, avail_str) = format_sizes(total, used, avail, args.units, args.block_size);
+
             let usage_pct = mount.usage_percentage().unwrap_or(0.0);
             let usage_str = format!("{:.0}%", usage_pct);
-            
+
             let colored_usage = if use_color {
                 if usage_pct >= 90.0 {
                     format!("\x1b[31m{}\x1b[0m", usage_str) // Red
@@ -73,7 +197,7 @@ pub mod table {
             } else {
                 usage_str
             };
-            
+
             println!("{:<30} {:>8} {:>8} {:>8} {:>5} {:>8} {}",
                 fs_name,
                 fs_type,
@@ -105,7 +229,19 @@ pub mod table {
         }
     }
     
-    fn format_sizes(total: u64, used: u64, avail: u64, units: Units) -> (String, String, String) {
+    fn format_sizes(
+        total: u64,
+        used: u64,
+        avail: u64,
+        units: Units,
+        block_size: Option<crate::blocksize::BlockSize>,
+    ) -> (String, String, String) {
+        // An explicit `--block-size`, or the DF_BLOCK_SIZE/BLOCK_SIZE/BLOCKSIZE
+        // env vars, always win over `--units`, same as `df --block-size`
+        // overrides `-h`/`-k`.
+        if let Some(bs) = crate::blocksize::BlockSize::resolve_opt(block_size) {
+            return (bs.format(total), bs.format(used), bs.format(avail));
+        }
         match units {
             Units::Binary => (
                 format_bytes_binary(total),
@@ -164,45 +300,241 @@ pub mod table {
     }
 }
 
+/// Quote a CSV field per RFC 4180: wrap it in double quotes (doubling any
+/// embedded quotes) whenever it contains the separator, a quote, or a CR/LF,
+/// so mount points and filesystem labels with commas or newlines in them
+/// can't corrupt the row.
+fn csv_field(value: &str, separator: char) -> String {
+    let needs_quoting = value.contains(separator)
+        || value.contains('"')
+        || value.contains('\r')
+        || value.contains('\n');
+    if needs_quoting {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_row(fields: &[String], separator: char) -> String {
+    fields
+        .iter()
+        .map(|f| csv_field(f, separator))
+        .collect::<Vec<_>>()
+        .join(&separator.to_string())
+}
+
+#[cfg(test)]
+mod csv_tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_plain_value_is_untouched() {
+        assert_eq!(csv_field("/dev/sda1", ','), "/dev/sda1");
+    }
+
+    #[test]
+    fn test_csv_field_quotes_separator() {
+        assert_eq!(csv_field("a,b", ','), "\"a,b\"");
+        assert_eq!(csv_field("a;b", ','), "a;b");
+        assert_eq!(csv_field("a;b", ';'), "\"a;b\"");
+    }
+
+    #[test]
+    fn test_csv_field_quotes_and_doubles_embedded_quotes() {
+        assert_eq!(csv_field("say \"hi\"", ','), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_csv_field_quotes_newlines() {
+        assert_eq!(csv_field("a\nb", ','), "\"a\nb\"");
+        assert_eq!(csv_field("a\rb", ','), "\"a\rb\"");
+    }
+
+    #[test]
+    fn test_csv_row_joins_with_separator() {
+        assert_eq!(csv_row(&["a".into(), "b,c".into(), "d".into()], ','), "a,\"b,c\",d");
+    }
+
+    #[test]
+    fn test_csv_field_quotes_separator_and_embedded_quotes_together() {
+        // a mount point like `/mnt/say "hi", please` needs both the doubled
+        // quotes and the wrapping quotes for the embedded separator
+        assert_eq!(csv_field("say \"hi\", please", ','), "\"say \"\"hi\"\", please\"");
+    }
+}
+
 pub(crate) fn print_generic_csv<T: MountLike>(mounts: &[T], args: &Args) -> Result<(), Box<dyn std::error::Error>> {
-    println!("filesystem,type,total_bytes,used_bytes,available_bytes,usage_percent,mount_point");
-    
+    let sep = args.csv_separator;
+
+    // `--posix` gets the exact same fixed 512-byte-block df columns as the
+    // text `print_posix` path, not the `--output`/default column set, so
+    // `--csv --posix` and plain `--posix` agree on what "POSIX" means.
+    if args.posix {
+        let block = crate::blocksize::BlockSize::from_bytes_per_block(512);
+        println!("{}", csv_row(&[
+            "Filesystem".into(), "512-blocks".into(), "Used".into(),
+            "Available".into(), "Capacity".into(), "Mounted on".into(),
+        ], sep));
+        for mount in mounts {
+            if let (Some(total), Some(used), Some(avail)) =
+                (mount.total_bytes(), mount.used_bytes(), mount.available_bytes())
+            {
+                let pct = mount.usage_percentage().unwrap_or(0.0);
+                println!("{}", csv_row(&[
+                    mount.filesystem_name(),
+                    block.blocks(total).to_string(),
+                    block.blocks(used).to_string(),
+                    block.blocks(avail).to_string(),
+                    format!("{:.0}%", pct),
+                    mount.mount_point(),
+                ], sep));
+            } else {
+                println!("{}", csv_row(&[
+                    mount.filesystem_name(), "-".into(), "-".into(), "-".into(), "-".into(), mount.mount_point(),
+                ], sep));
+            }
+        }
+        if args.total {
+            if let Some((total, used, avail, usage_pct)) = table::sum_totals(mounts) {
+                println!("{}", csv_row(&[
+                    "total".into(),
+                    block.blocks(total).to_string(),
+                    block.blocks(used).to_string(),
+                    block.blocks(avail).to_string(),
+                    format!("{:.0}%", usage_pct),
+                    "".into(),
+                ], sep));
+            }
+        }
+        return Ok(());
+    }
+
+    if let Some(fields) = &args.output_fields {
+        println!("{}", csv_row(&fields.iter().map(|c| c.title().to_string()).collect::<Vec<_>>(), sep));
+        for mount in mounts {
+            println!("{}", csv_row(&fields.iter().map(|c| table::generic_cell(mount, *c, args.units, args.block_size)).collect::<Vec<_>>(), sep));
+        }
+        if args.total {
+            if let Some((total, used, avail, usage_pct)) = table::sum_totals(mounts) {
+                println!("{}", csv_row(&fields.iter().map(|c| table::total_row_cell(*c, total, used, avail, usage_pct, args.units, args.block_size)).collect::<Vec<_>>(), sep));
+            }
+        }
+        return Ok(());
+    }
+
+    println!("{}", csv_row(&[
+        "filesystem".into(), "type".into(), "total_bytes".into(), "used_bytes".into(),
+        "available_bytes".into(), "usage_percent".into(), "mount_point".into(),
+    ], sep));
+
     for mount in mounts {
-        println!("{},{},{},{},{},{:.2},{}",
+        // An unreachable mount gets an explicit "unreachable" token rather
+        // than a bare 0, which would be indistinguishable from a genuinely
+        // empty volume.
+        let unreachable = mount.is_unreachable();
+        let field = |v: Option<String>| {
+            if unreachable {
+                "unreachable".to_string()
+            } else {
+                v.unwrap_or_default()
+            }
+        };
+        println!("{}", csv_row(&[
             mount.filesystem_name(),
             mount.filesystem_type(),
-            mount.total_bytes().unwrap_or(0),
-            mount.used_bytes().unwrap_or(0),
-            mount.available_bytes().unwrap_or(0),
-            mount.usage_percentage().unwrap_or(0.0),
-            mount.mount_point());
+            field(mount.total_bytes().map(|v| v.to_string())),
+            field(mount.used_bytes().map(|v| v.to_string())),
+            field(mount.available_bytes().map(|v| v.to_string())),
+            field(mount.usage_percentage().map(|v| format!("{:.2}", v))),
+            mount.mount_point(),
+        ], sep));
     }
-    
+
+    if args.total {
+        if let Some((total, used, avail, usage_pct)) = table::sum_totals(mounts) {
+            println!("{}", csv_row(&[
+                "total".into(), "".into(), total.to_string(), used.to_string(),
+                avail.to_string(), format!("{:.2}", usage_pct), "".into(),
+            ], sep));
+        }
+    }
+
     Ok(())
 }
 
-pub(crate) fn print_generic_json<T: MountLike>(mounts: &[T], args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+/// Build the `--json` output value: split out of `print_generic_json` so
+/// `json::output_value` (the function the real `--json` path calls) can
+/// reuse the same `--output`/`--total` handling instead of duplicating it.
+pub(crate) fn build_generic_json_value<T: MountLike>(mounts: &[T], args: &Args) -> serde_json::Value {
     use serde_json::{json, Value};
-    
-    let mount_data: Vec<Value> = mounts.iter().map(|mount| {
-        json!({
-            "filesystem": mount.filesystem_name(),
-            "type": mount.filesystem_type(),
-            "total_bytes": mount.total_bytes(),
-            "used_bytes": mount.used_bytes(),
-            "available_bytes": mount.available_bytes(),
-            "usage_percentage": mount.usage_percentage(),
-            "mount_point": mount.mount_point()
-        })
-    }).collect();
-    
-    let output = json!({ "mounts": mount_data });
-    println!("{}", serde_json::to_string_pretty(&output)?);
-    
+
+    let mount_data: Vec<Value> = if let Some(fields) = &args.output_fields {
+        mounts.iter().map(|mount| {
+            let mut obj = serde_json::Map::new();
+            for col in fields {
+                obj.insert(col.title().to_lowercase(), json!(table::generic_cell(mount, *col, args.units, args.block_size)));
+            }
+            Value::Object(obj)
+        }).collect()
+    } else {
+        mounts.iter().map(|mount| {
+            json!({
+                "filesystem": mount.filesystem_name(),
+                "type": mount.filesystem_type(),
+                "total_bytes": mount.total_bytes(),
+                "used_bytes": mount.used_bytes(),
+                "available_bytes": mount.available_bytes(),
+                "usage_percentage": mount.usage_percentage(),
+                "mount_point": mount.mount_point(),
+                "unreachable": mount.is_unreachable()
+            })
+        }).collect()
+    };
+
+    let total = args.total.then(|| table::sum_totals(mounts)).flatten().map(
+        |(total, used, avail, usage_pct)| {
+            json!({
+                "filesystem": "total",
+                "type": Value::Null,
+                "total_bytes": total,
+                "used_bytes": used,
+                "available_bytes": avail,
+                "usage_percentage": usage_pct,
+                "mount_point": Value::Null
+            })
+        },
+    );
+
+    match total {
+        Some(total) => json!({ "mounts": mount_data, "total": total }),
+        None => json!({ "mounts": mount_data }),
+    }
+}
+
+pub(crate) fn print_generic_json<T: MountLike>(mounts: &[T], args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", serde_json::to_string_pretty(&build_generic_json_value(mounts, args))?);
     Ok(())
 }
 
+/// Resolve a byte amount to display text, honoring `--block-size` (and its
+/// `DF_BLOCK_SIZE`/`BLOCK_SIZE`/`BLOCKSIZE` env fallbacks) over `--units`,
+/// the same precedence `format_sizes` uses for the generic text rows.
+fn format_amount(bytes: u64, units: Units, block_size: Option<crate::blocksize::BlockSize>) -> String {
+    match crate::blocksize::BlockSize::resolve_opt(block_size) {
+        Some(bs) => bs.format(bytes),
+        None => units.fmt(bytes),
+    }
+}
+
 pub fn print(mounts: &[&Mount], color: bool, args: &Args) {
+    // `--posix` and `--output` both render a different, fixed column set
+    // than the termimad table below; reuse the `MountLike`-generic path
+    // that already implements them instead of duplicating it here.
+    if args.posix || args.output_fields.is_some() {
+        let owned: Vec<Mount> = mounts.iter().map(|m| (*m).clone()).collect();
+        return table::print_generic(&owned, color, args);
+    }
     if args.cols.is_empty() {
         return;
     }
@@ -231,11 +563,11 @@ pub fn print(mounts: &[&Mount], color: bool, args: &Args) {
             let use_share = stats.use_share();
             let free_share = 1.0 - use_share;
             sub
-                .set("size", units.fmt(stats.size()))
-                .set("used", units.fmt(stats.used()))
+                .set("size", format_amount(stats.size(), units, args.block_size))
+                .set("used", format_amount(stats.used(), units, args.block_size))
                 .set("use-percents", format!("{:.0}%", 100.0 * use_share))
                 .set_md("bar", progress_bar_md(use_share, BAR_WIDTH, args.ascii))
-                .set("free", units.fmt(stats.available()))
+                .set("free", format_amount(stats.available(), units, args.block_size))
                 .set("free-percents", format!("{:.0}%", 100.0 * free_share));
             if let Some(inodes) = &stats.inodes {
                 let iuse_share = inodes.use_share();
@@ -250,6 +582,21 @@ pub fn print(mounts: &[&Mount], color: bool, args: &Args) {
             sub.set("use-error", "unreachable");
         }
     }
+    if args.total {
+        if let Some((total, used, avail, usage_pct)) = sum_mount_totals(mounts) {
+            let use_share = usage_pct / 100.0;
+            let free_share = 1.0 - use_share;
+            expander
+                .sub("rows")
+                .set("filesystem", "total")
+                .set("size", format_amount(total, units, args.block_size))
+                .set("used", format_amount(used, units, args.block_size))
+                .set("use-percents", format!("{:.0}%", usage_pct))
+                .set_md("bar", progress_bar_md(use_share, BAR_WIDTH, args.ascii))
+                .set("free", format_amount(avail, units, args.block_size))
+                .set("free-percents", format!("{:.0}%", 100.0 * free_share));
+        }
+    }
     let mut skin = if color {
         make_colored_skin()
     } else {
@@ -296,6 +643,29 @@ pub fn print(mounts: &[&Mount], color: bool, args: &Args) {
     skin.print_owning_expander_md(&expander, &tbl);
 }
 
+/// Same aggregate as `table::sum_totals`, but for the concrete `&[&Mount]`
+/// slice used by the minimad-rendered `print`, which doesn't go through the
+/// generic `MountLike` path.
+fn sum_mount_totals(mounts: &[&Mount]) -> Option<(u64, u64, u64, f64)> {
+    let mut total = 0u64;
+    let mut used = 0u64;
+    let mut avail = 0u64;
+    let mut any = false;
+    for mount in mounts {
+        if let Some(stats) = mount.stats() {
+            total += stats.size();
+            used += stats.used();
+            avail += stats.available();
+            any = true;
+        }
+    }
+    if !any {
+        return None;
+    }
+    let pct = if total > 0 { used as f64 / total as f64 * 100.0 } else { 0.0 };
+    Some((total, used, avail, pct))
+}
+
 fn make_colored_skin() -> MadSkin {
     MadSkin {
         bold: CompoundStyle::with_fg(AnsiValue(SIZE_COLOR)), // size